@@ -0,0 +1,237 @@
+use glam::{Vec3, Vec4Swizzles};
+
+use crate::{ColorAlpha, LinearColorSpace, Premultiplied};
+
+/// A Porter-Duff compositing operator.
+///
+/// Each operator is defined by a pair of coverage coefficients `Fa`, `Fb` which are combined
+/// with the premultiplied source and destination colors as `Co = Cs*Fa + Cd*Fb` and
+/// `ao = as*Fa + ad*Fb`. See Porter and Duff, "Compositing Digital Images" (1984).
+pub trait PorterDuff {
+    /// Computes the `(Fa, Fb)` coverage coefficients given the source alpha `as_` and
+    /// destination alpha `ad`.
+    fn coefficients(as_: f32, ad: f32) -> (f32, f32);
+}
+
+macro_rules! porter_duff_op {
+    ($name:ident, |$as_:ident, $ad:ident| ($fa:expr, $fb:expr)) => {
+        /// A [`PorterDuff`] compositing operator.
+        pub struct $name;
+
+        impl PorterDuff for $name {
+            #[inline]
+            fn coefficients($as_: f32, $ad: f32) -> (f32, f32) {
+                ($fa, $fb)
+            }
+        }
+    };
+}
+
+porter_duff_op!(Over, |as_, _ad| (1.0, 1.0 - as_));
+porter_duff_op!(In, |_as_, ad| (ad, 0.0));
+porter_duff_op!(Out, |_as_, ad| (1.0 - ad, 0.0));
+porter_duff_op!(Atop, |as_, ad| (ad, 1.0 - as_));
+porter_duff_op!(Xor, |as_, ad| (1.0 - ad, 1.0 - as_));
+porter_duff_op!(Plus, |_as_, _ad| (1.0, 1.0));
+porter_duff_op!(Src, |_as_, _ad| (1.0, 0.0));
+porter_duff_op!(Dst, |_as_, _ad| (0.0, 1.0));
+
+/// A separable blend mode, as defined by the W3C Compositing and Blending spec.
+///
+/// `blend` operates on *un-premultiplied* backdrop (`cb`) and source (`cs`) values and is
+/// applied per-channel.
+pub trait SeparableBlendMode {
+    /// Blends a single un-premultiplied channel of the backdrop `cb` with the source `cs`.
+    fn blend(cb: f32, cs: f32) -> f32;
+}
+
+macro_rules! separable_blend_mode {
+    ($name:ident, |$cb:ident, $cs:ident| $body:expr) => {
+        /// A [`SeparableBlendMode`].
+        pub struct $name;
+
+        impl SeparableBlendMode for $name {
+            #[inline]
+            fn blend($cb: f32, $cs: f32) -> f32 {
+                $body
+            }
+        }
+    };
+}
+
+separable_blend_mode!(Multiply, |cb, cs| cb * cs);
+separable_blend_mode!(Screen, |cb, cs| cb + cs - cb * cs);
+separable_blend_mode!(Overlay, |cb, cs| HardLight::blend(cs, cb));
+separable_blend_mode!(Darken, |cb, cs| cb.min(cs));
+separable_blend_mode!(Lighten, |cb, cs| cb.max(cs));
+separable_blend_mode!(ColorDodge, |cb, cs| {
+    if cb == 0.0 {
+        0.0
+    } else if cs == 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+});
+separable_blend_mode!(ColorBurn, |cb, cs| {
+    if cb == 1.0 {
+        1.0
+    } else if cs == 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+});
+separable_blend_mode!(HardLight, |cb, cs| {
+    if cs <= 0.5 {
+        Multiply::blend(cb, 2.0 * cs)
+    } else {
+        Screen::blend(cb, 2.0 * cs - 1.0)
+    }
+});
+separable_blend_mode!(SoftLight, |cb, cs| {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        let d = if cb <= 0.25 {
+            ((16.0 * cb - 12.0) * cb + 4.0) * cb
+        } else {
+            cb.sqrt()
+        };
+        cb + (2.0 * cs - 1.0) * (d - cb)
+    }
+});
+separable_blend_mode!(Difference, |cb, cs| (cb - cs).abs());
+separable_blend_mode!(Exclusion, |cb, cs| cb + cs - 2.0 * cb * cs);
+
+/// Un-premultiplies `raw` by `alpha`, short-circuiting to the raw color itself when `alpha` is
+/// zero to avoid producing `Inf`/`NaN`.
+#[inline]
+fn unpremultiply(raw: Vec3, alpha: f32) -> Vec3 {
+    if alpha == 0.0 {
+        raw
+    } else {
+        raw / alpha
+    }
+}
+
+impl<Spc: LinearColorSpace> ColorAlpha<Spc, Premultiplied> {
+    /// Composites `self` (the source) over `dst` (the destination) using the given
+    /// [`PorterDuff`] operator `Op`.
+    ///
+    /// Both colors must be premultiplied, which this operates on directly, since the Porter-Duff
+    /// equations are only correct in premultiplied form.
+    pub fn composite<Op: PorterDuff>(
+        self,
+        dst: ColorAlpha<Spc, Premultiplied>,
+    ) -> ColorAlpha<Spc, Premultiplied> {
+        let as_ = self.raw.w;
+        let ad = dst.raw.w;
+        let (fa, fb) = Op::coefficients(as_, ad);
+
+        let co = self.raw.xyz() * fa + dst.raw.xyz() * fb;
+        let ao = as_ * fa + ad * fb;
+
+        ColorAlpha::from_raw(co.extend(ao))
+    }
+
+    /// Blends `self` (the source) with `dst` (the destination) using the separable blend mode
+    /// `B`, then composites the result `Over` `dst`.
+    ///
+    /// Follows the W3C Compositing and Blending formula
+    /// `Co = (1-ad)*as*Cs + (1-as)*ad*Cd + as*ad*B(Cb,Cs)`, `ao = as + ad*(1-as)`. The blend
+    /// function itself is evaluated on un-premultiplied color, per the spec.
+    pub fn blend_mode<B: SeparableBlendMode>(
+        self,
+        dst: ColorAlpha<Spc, Premultiplied>,
+    ) -> ColorAlpha<Spc, Premultiplied> {
+        let as_ = self.raw.w;
+        let ad = dst.raw.w;
+
+        let cs = unpremultiply(self.raw.xyz(), as_);
+        let cb = unpremultiply(dst.raw.xyz(), ad);
+
+        let blended = Vec3::new(
+            B::blend(cb.x, cs.x),
+            B::blend(cb.y, cs.y),
+            B::blend(cb.z, cs.z),
+        );
+
+        let co = (1.0 - ad) * as_ * cs + (1.0 - as_) * ad * cb + as_ * ad * blended;
+        let ao = as_ + ad * (1.0 - as_);
+
+        ColorAlpha::from_raw(co.extend(ao))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearSrgb;
+    use glam::Vec4;
+
+    fn assert_vec4_close(a: Vec4, b: Vec4) {
+        assert!((a - b).abs().max_element() < 1e-5, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn over_opaque_source_fully_covers_dest() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(1.0, 0.0, 0.0).extend(1.0));
+        let dst = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(0.0, 0.0, 1.0).extend(1.0));
+
+        let result = src.composite::<Over>(dst);
+
+        assert_vec4_close(result.raw, Vec3::new(1.0, 0.0, 0.0).extend(1.0));
+    }
+
+    #[test]
+    fn over_partial_alpha_blends_with_dest() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(0.5, 0.0, 0.0).extend(0.5));
+        let dst = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(0.0, 1.0, 0.0).extend(1.0));
+
+        let result = src.composite::<Over>(dst);
+
+        assert_vec4_close(result.raw, Vec3::new(0.5, 0.5, 0.0).extend(1.0));
+    }
+
+    #[test]
+    fn in_clips_source_to_dest_coverage() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(1.0, 0.0, 0.0).extend(1.0));
+        let dst = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::new(0.0, 0.0, 1.0).extend(0.5));
+
+        let result = src.composite::<In>(dst);
+
+        assert_vec4_close(result.raw, Vec3::new(0.5, 0.0, 0.0).extend(0.5));
+    }
+
+    #[test]
+    fn multiply_of_white_and_gray_is_gray() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::ONE.extend(1.0));
+        let dst =
+            ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::splat(0.5).extend(1.0));
+
+        let result = src.blend_mode::<Multiply>(dst);
+
+        assert_vec4_close(result.raw, Vec3::splat(0.5).extend(1.0));
+    }
+
+    #[test]
+    fn screen_of_black_and_white_is_white() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::ZERO.extend(1.0));
+        let dst = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::ONE.extend(1.0));
+
+        let result = src.blend_mode::<Screen>(dst);
+
+        assert_vec4_close(result.raw, Vec3::ONE.extend(1.0));
+    }
+
+    #[test]
+    fn blend_mode_with_zero_alpha_does_not_produce_nan() {
+        let src = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec4::ZERO);
+        let dst = ColorAlpha::<LinearSrgb, Premultiplied>::from_raw(Vec3::splat(0.5).extend(1.0));
+
+        let result = src.blend_mode::<Multiply>(dst);
+
+        assert!(result.raw.is_finite());
+    }
+}
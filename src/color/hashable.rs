@@ -0,0 +1,114 @@
+use core::hash::{Hash, Hasher};
+
+use crate::ColorAlpha;
+
+/// A [`ColorAlpha`] wrapper with a total, NaN-aware [`Eq`] and [`Hash`], so colors can key a
+/// `HashMap`/`HashSet` for e.g. palette deduplication or caching.
+///
+/// `ColorAlpha` itself only derives `PartialEq` via raw `f32` comparison (so `NaN != NaN`) and
+/// has no `Hash` at all. This wrapper instead hashes each lane's bit pattern, canonicalizing
+/// `-0.0`/`+0.0` to the same value and collapsing all NaNs into a single bucket, following the
+/// approach used by `ecolor`. Reach for this only when you specifically need map/set semantics;
+/// everywhere else prefer the normal `f32` equality `ColorAlpha` already provides.
+#[derive(Copy, Clone)]
+pub struct HashableColorAlpha<Spc, A>(pub ColorAlpha<Spc, A>);
+
+impl<Spc, A> HashableColorAlpha<Spc, A> {
+    /// Wraps `color` for use as a map/set key.
+    pub fn new(color: ColorAlpha<Spc, A>) -> Self {
+        Self(color)
+    }
+
+    /// Unwraps back to the underlying [`ColorAlpha`].
+    pub fn into_inner(self) -> ColorAlpha<Spc, A> {
+        self.0
+    }
+}
+
+impl<Spc, A> From<ColorAlpha<Spc, A>> for HashableColorAlpha<Spc, A> {
+    fn from(color: ColorAlpha<Spc, A>) -> Self {
+        Self::new(color)
+    }
+}
+
+/// Canonicalizes `x` for bitwise comparison/hashing: collapses all NaNs to a single bit pattern
+/// and `-0.0` to `+0.0`, so that values with different bit patterns (including NaNs, which compare
+/// unequal under IEEE-754 `f32` equality) but which are indistinguishable to a human still hash and
+/// compare equal here.
+#[inline]
+fn canonicalize_bits(x: f32) -> u32 {
+    if x.is_nan() {
+        f32::NAN.to_bits()
+    } else if x == 0.0 {
+        0.0f32.to_bits()
+    } else {
+        x.to_bits()
+    }
+}
+
+impl<Spc, A> PartialEq for HashableColorAlpha<Spc, A> {
+    fn eq(&self, other: &Self) -> bool {
+        let a = self.0.raw;
+        let b = other.0.raw;
+        canonicalize_bits(a.x) == canonicalize_bits(b.x)
+            && canonicalize_bits(a.y) == canonicalize_bits(b.y)
+            && canonicalize_bits(a.z) == canonicalize_bits(b.z)
+            && canonicalize_bits(a.w) == canonicalize_bits(b.w)
+    }
+}
+
+impl<Spc, A> Eq for HashableColorAlpha<Spc, A> {}
+
+impl<Spc, A> Hash for HashableColorAlpha<Spc, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let raw = self.0.raw;
+        canonicalize_bits(raw.x).hash(state);
+        canonicalize_bits(raw.y).hash(state);
+        canonicalize_bits(raw.z).hash(state);
+        canonicalize_bits(raw.w).hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearSrgb;
+    use glam::Vec4;
+
+    fn hashable(raw: Vec4) -> HashableColorAlpha<LinearSrgb, crate::Separate> {
+        HashableColorAlpha::new(ColorAlpha::from_raw(raw))
+    }
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn negative_and_positive_zero_are_equal_and_hash_equal() {
+        let a = hashable(Vec4::new(-0.0, 0.0, -0.0, 0.0));
+        let b = hashable(Vec4::new(0.0, -0.0, 0.0, -0.0));
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn different_nan_bit_patterns_are_equal_and_hash_equal() {
+        let a = hashable(Vec4::new(f32::NAN, 0.0, 0.0, 1.0));
+        let b = hashable(Vec4::new(f32::from_bits(f32::NAN.to_bits() ^ 1), 0.0, 0.0, 1.0));
+
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn distinct_colors_are_not_equal() {
+        let a = hashable(Vec4::new(1.0, 0.0, 0.0, 1.0));
+        let b = hashable(Vec4::new(0.0, 1.0, 0.0, 1.0));
+
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{AlphaState, AsU8Array, ColorAlpha};
+
+/// A channel ordering for packing a color's four `u8` lanes into a single `u32`, used by
+/// [`ColorAlpha::to_u32`]/[`ColorAlpha::from_u32`].
+///
+/// Implementing this directly lets you interoperate with GPU texture formats or OS framebuffer
+/// layouts that `colstodian` doesn't provide a marker for out of the box.
+pub trait ChannelOrder {
+    /// Packs the `[r, g, b, a]` lanes produced by [`ColorAlpha::to_u8`] into a `u32`.
+    fn pack(rgba: [u8; 4]) -> u32;
+
+    /// Unpacks a `u32` produced by [`Self::pack`] back into `[r, g, b, a]` lanes.
+    fn unpack(packed: u32) -> [u8; 4];
+}
+
+macro_rules! channel_order {
+    ($name:ident, |$r:ident, $g:ident, $b:ident, $a:ident| $pack:expr, |$packed:ident| [$ur:expr, $ug:expr, $ub:expr, $ua:expr]) => {
+        /// A [`ChannelOrder`].
+        pub struct $name;
+
+        impl ChannelOrder for $name {
+            #[inline]
+            fn pack([$r, $g, $b, $a]: [u8; 4]) -> u32 {
+                $pack
+            }
+
+            #[inline]
+            fn unpack($packed: u32) -> [u8; 4] {
+                [$ur, $ug, $ub, $ua]
+            }
+        }
+    };
+}
+
+channel_order!(
+    Argb,
+    |r, g, b, a| (a as u32) << 24 | (r as u32) << 16 | (g as u32) << 8 | b as u32,
+    |packed| [
+        (packed >> 16) as u8,
+        (packed >> 8) as u8,
+        packed as u8,
+        (packed >> 24) as u8
+    ]
+);
+
+channel_order!(
+    Rgba,
+    |r, g, b, a| (r as u32) << 24 | (g as u32) << 16 | (b as u32) << 8 | a as u32,
+    |packed| [
+        (packed >> 24) as u8,
+        (packed >> 16) as u8,
+        (packed >> 8) as u8,
+        packed as u8
+    ]
+);
+
+channel_order!(
+    Abgr,
+    |r, g, b, a| (a as u32) << 24 | (b as u32) << 16 | (g as u32) << 8 | r as u32,
+    |packed| [
+        packed as u8,
+        (packed >> 8) as u8,
+        (packed >> 16) as u8,
+        (packed >> 24) as u8
+    ]
+);
+
+channel_order!(
+    Bgra,
+    |r, g, b, a| (b as u32) << 24 | (g as u32) << 16 | (r as u32) << 8 | a as u32,
+    |packed| [
+        (packed >> 8) as u8,
+        (packed >> 16) as u8,
+        (packed >> 24) as u8,
+        packed as u8
+    ]
+);
+
+impl<Spc: AsU8Array, A: AlphaState> ColorAlpha<Spc, A> {
+    /// Packs `self` into a `u32` with the given [`ChannelOrder`]. All components of `self`
+    /// *must* be in range `[0..1]`.
+    ///
+    /// This reuses [`to_u8`][ColorAlpha::to_u8] and then shifts each lane into place, so the
+    /// resulting bit layout is explicit and doesn't depend on the host's endianness the way a
+    /// raw transmute would.
+    pub fn to_u32<Order: ChannelOrder>(self) -> u32 {
+        Order::pack(self.to_u8())
+    }
+
+    /// Unpacks a `u32` produced by [`to_u32`][ColorAlpha::to_u32] with the given
+    /// [`ChannelOrder`] back into a `ColorAlpha`.
+    pub fn from_u32<Order: ChannelOrder>(packed: u32) -> Self {
+        Self::from_u8(Order::unpack(packed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EncodedSrgb, Separate};
+
+    fn color(r: u8, g: u8, b: u8, a: u8) -> ColorAlpha<EncodedSrgb, Separate> {
+        ColorAlpha::from_u8([r, g, b, a])
+    }
+
+    #[test]
+    fn argb_packs_alpha_in_top_byte() {
+        let packed = color(0x11, 0x22, 0x33, 0x44).to_u32::<Argb>();
+        assert_eq!(packed, 0x44112233);
+    }
+
+    #[test]
+    fn rgba_packs_alpha_in_bottom_byte() {
+        let packed = color(0x11, 0x22, 0x33, 0x44).to_u32::<Rgba>();
+        assert_eq!(packed, 0x11223344);
+    }
+
+    #[test]
+    fn abgr_reverses_color_channels() {
+        let packed = color(0x11, 0x22, 0x33, 0x44).to_u32::<Abgr>();
+        assert_eq!(packed, 0x44332211);
+    }
+
+    #[test]
+    fn bgra_reverses_color_channels_with_trailing_alpha() {
+        let packed = color(0x11, 0x22, 0x33, 0x44).to_u32::<Bgra>();
+        assert_eq!(packed, 0x33221144);
+    }
+
+    #[test]
+    fn argb_round_trips() {
+        let original = color(0x11, 0x22, 0x33, 0x44);
+        let round_tripped =
+            ColorAlpha::<EncodedSrgb, Separate>::from_u32::<Argb>(original.to_u32::<Argb>());
+        assert_eq!(original.raw, round_tripped.raw);
+    }
+
+    #[test]
+    fn rgba_round_trips() {
+        let original = color(0x11, 0x22, 0x33, 0x44);
+        let round_tripped =
+            ColorAlpha::<EncodedSrgb, Separate>::from_u32::<Rgba>(original.to_u32::<Rgba>());
+        assert_eq!(original.raw, round_tripped.raw);
+    }
+
+    #[test]
+    fn abgr_round_trips() {
+        let original = color(0x11, 0x22, 0x33, 0x44);
+        let round_tripped =
+            ColorAlpha::<EncodedSrgb, Separate>::from_u32::<Abgr>(original.to_u32::<Abgr>());
+        assert_eq!(original.raw, round_tripped.raw);
+    }
+
+    #[test]
+    fn bgra_round_trips() {
+        let original = color(0x11, 0x22, 0x33, 0x44);
+        let round_tripped =
+            ColorAlpha::<EncodedSrgb, Separate>::from_u32::<Bgra>(original.to_u32::<Bgra>());
+        assert_eq!(original.raw, round_tripped.raw);
+    }
+}
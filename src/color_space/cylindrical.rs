@@ -0,0 +1,565 @@
+use glam::{Vec3, Vec4Swizzles};
+
+use crate::{Color, ColorAlpha, Display, EncodedSrgb, LinearSrgb, Separate};
+
+// NOTE: `Hsl`/`Hsv`/`Lch` are deliberately *not* registered as `ColorSpace`s. `ConvertFromRaw` is
+// implemented generically for every `ColorSpace` pair in terms of `kolor`'s transform-function
+// tables, keyed by each space's `ColorSpace::SPACE: DynamicColorSpace` (a `kolor::ColorSpace`
+// value). `kolor` is an external dependency whose color space enum is closed over its own
+// primitives and has no cylindrical variants, so there is no `DynamicColorSpace` we could
+// legitimately give these types, and a hand-written `ConvertFromRaw` impl for e.g. `(LinearSrgb,
+// Hsl)` would conflict with the blanket impl. Making `Color<Hsl, _>`/`ColorAlpha<Hsl, _>` and
+// `.convert::<Hsl>()` actually exist would require upstream changes to `kolor` itself.
+//
+// All three models instead get the same two-method entry point: `to_linear_srgb`/
+// `from_linear_srgb` (`to_lab`/`from_lab` for `Lch`, one step further down the pipe), so
+// `color.to_hsl()`/`.to_hsv()`/`.to_lch()`-style round trips all exist and none is a dead end.
+// `Hue`/`Saturate`/`Shade` are implemented for all three *value types* themselves (below), so
+// `hsv.shift_hue(30.0)`/`lch.lighten(0.1)` work directly. Rust's coherence rules mean only one
+// model can additionally get the `color.shift_hue(30.0)` sugar directly on
+// `Color<LinearSrgb, Display>`/`ColorAlpha<LinearSrgb, Separate>` themselves (a blanket impl of
+// the same trait for the same concrete type can't be repeated for a second model) — `Hsl` was
+// chosen as that default, mirroring Bevy's `Hsla`. For `Hsv`/`Lch`, go through the explicit
+// `to_hsv()`/`to_lch()` conversion first, e.g. `color.to_hsv().shift_hue(30.0).to_linear_srgb()`.
+
+/// Hue, saturation, lightness. A cylindrical re-parameterization of [`LinearSrgb`], useful for
+/// artist-facing adjustments that are awkward to express directly in RGB.
+///
+/// `hue` is in degrees `[0..360)`, `saturation` and `lightness` are `[0..1]`. Achromatic colors
+/// (`saturation == 0.0`) have an undefined `hue`, which this crate represents as `0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsl {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+}
+
+/// Hue, saturation, value. A cylindrical re-parameterization of [`LinearSrgb`] that matches the
+/// "color picker" model most artists are already familiar with.
+///
+/// `hue` is in degrees `[0..360)`, `saturation` and `value` are `[0..1]`. Achromatic colors
+/// (`saturation == 0.0`) have an undefined `hue`, which this crate represents as `0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hsv {
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+}
+
+/// Lightness, chroma, hue. A cylindrical re-parameterization of CIELAB, perceptually uniform
+/// (unlike [`Hsl`]/[`Hsv`]) since it inherits that property from Lab.
+///
+/// `lightness` is `[0..100]`, `chroma` is unbounded but typically `[0..~150]`, `hue` is in
+/// degrees `[0..360)`. Achromatic colors (`chroma == 0.0`) have an undefined `hue`, which this
+/// crate represents as `0.0`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lch {
+    pub lightness: f32,
+    pub chroma: f32,
+    pub hue: f32,
+}
+
+impl Hsl {
+    /// Converts `self` to a [`LinearSrgb`] color using the standard hexcone algorithm.
+    pub fn to_linear_srgb(self) -> Color<LinearSrgb, Display> {
+        let Hsl {
+            hue,
+            saturation,
+            lightness,
+        } = self;
+
+        let c = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let m = lightness - c * 0.5;
+        Color::from_raw(hexcone(hue, c, m))
+    }
+
+    /// Converts a [`LinearSrgb`] color to [`Hsl`].
+    pub fn from_linear_srgb(color: Color<LinearSrgb, Display>) -> Self {
+        let (hue, chroma, max, min) = hue_chroma(color.raw);
+        let lightness = (max + min) * 0.5;
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl Hsv {
+    /// Converts `self` to a [`LinearSrgb`] color using the standard hexcone algorithm.
+    pub fn to_linear_srgb(self) -> Color<LinearSrgb, Display> {
+        let Hsv {
+            hue,
+            saturation,
+            value,
+        } = self;
+
+        let c = value * saturation;
+        let m = value - c;
+        Color::from_raw(hexcone(hue, c, m))
+    }
+
+    /// Converts a [`LinearSrgb`] color to [`Hsv`].
+    pub fn from_linear_srgb(color: Color<LinearSrgb, Display>) -> Self {
+        let (hue, chroma, max, _min) = hue_chroma(color.raw);
+        let value = max;
+        let saturation = if value == 0.0 { 0.0 } else { chroma / value };
+
+        Hsv {
+            hue,
+            saturation,
+            value,
+        }
+    }
+}
+
+impl Lch {
+    /// Converts `self` to a CIELAB color, represented as the raw `(L, a, b)` triple.
+    pub fn to_lab(self) -> Vec3 {
+        let Lch {
+            lightness,
+            chroma,
+            hue,
+        } = self;
+        let hue_rad = hue.to_radians();
+        Vec3::new(lightness, chroma * hue_rad.cos(), chroma * hue_rad.sin())
+    }
+
+    /// Converts a CIELAB color, represented as the raw `(L, a, b)` triple, to [`Lch`].
+    pub fn from_lab(lab: Vec3) -> Self {
+        let chroma = lab.y.hypot(lab.z);
+        let hue = if chroma == 0.0 {
+            0.0
+        } else {
+            let deg = lab.z.atan2(lab.y).to_degrees();
+            if deg < 0.0 {
+                deg + 360.0
+            } else {
+                deg
+            }
+        };
+
+        Lch {
+            lightness: lab.x,
+            chroma,
+            hue,
+        }
+    }
+
+    /// Converts `self` to a [`LinearSrgb`] color, via CIELAB and CIE XYZ (D65).
+    pub fn to_linear_srgb(self) -> Color<LinearSrgb, Display> {
+        Color::from_raw(lab_to_linear_srgb(self.to_lab()))
+    }
+
+    /// Converts a [`LinearSrgb`] color to [`Lch`], via CIE XYZ (D65) and CIELAB.
+    pub fn from_linear_srgb(color: Color<LinearSrgb, Display>) -> Self {
+        Lch::from_lab(linear_srgb_to_lab(color.raw))
+    }
+}
+
+impl Color<LinearSrgb, Display> {
+    /// Converts `self` to [`Hsl`]. Shorthand for [`Hsl::from_linear_srgb`].
+    pub fn to_hsl(self) -> Hsl {
+        Hsl::from_linear_srgb(self)
+    }
+
+    /// Converts `self` to [`Hsv`]. Shorthand for [`Hsv::from_linear_srgb`].
+    pub fn to_hsv(self) -> Hsv {
+        Hsv::from_linear_srgb(self)
+    }
+
+    /// Converts `self` to [`Lch`]. Shorthand for [`Lch::from_linear_srgb`].
+    pub fn to_lch(self) -> Lch {
+        Lch::from_linear_srgb(self)
+    }
+}
+
+/// Converts an [`EncodedSrgb`] color directly to [`Hsl`], without making the caller linearize it
+/// first.
+pub fn encoded_srgb_to_hsl(color: Color<EncodedSrgb, Display>) -> Hsl {
+    Hsl::from_linear_srgb(color.convert::<LinearSrgb>())
+}
+
+/// D65 reference white, used by [`linear_srgb_to_lab`]/[`lab_to_linear_srgb`].
+const D65_WHITE: Vec3 = Vec3::new(0.95047, 1.0, 1.08883);
+
+/// Converts linear sRGB to CIE XYZ (D65), using the standard sRGB primaries matrix.
+fn linear_srgb_to_xyz(rgb: Vec3) -> Vec3 {
+    Vec3::new(
+        0.4124564 * rgb.x + 0.3575761 * rgb.y + 0.1804375 * rgb.z,
+        0.2126729 * rgb.x + 0.7151522 * rgb.y + 0.0721750 * rgb.z,
+        0.0193339 * rgb.x + 0.1191920 * rgb.y + 0.9503041 * rgb.z,
+    )
+}
+
+/// Converts CIE XYZ (D65) to linear sRGB, the inverse of [`linear_srgb_to_xyz`].
+fn xyz_to_linear_srgb(xyz: Vec3) -> Vec3 {
+    Vec3::new(
+        3.2404542 * xyz.x - 1.5371385 * xyz.y - 0.4985314 * xyz.z,
+        -0.9692660 * xyz.x + 1.8760108 * xyz.y + 0.0415560 * xyz.z,
+        0.0556434 * xyz.x - 0.2040259 * xyz.y + 1.0572252 * xyz.z,
+    )
+}
+
+/// Converts linear sRGB directly to CIELAB (D65), composing [`linear_srgb_to_xyz`] with the
+/// standard XYZ→Lab transform.
+fn linear_srgb_to_lab(rgb: Vec3) -> Vec3 {
+    fn f(t: f32) -> f32 {
+        const DELTA: f32 = 6.0 / 29.0;
+        if t > DELTA * DELTA * DELTA {
+            t.cbrt()
+        } else {
+            t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+        }
+    }
+
+    let xyz = linear_srgb_to_xyz(rgb) / D65_WHITE;
+    let (fx, fy, fz) = (f(xyz.x), f(xyz.y), f(xyz.z));
+
+    Vec3::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Converts CIELAB (D65) directly to linear sRGB, the inverse of [`linear_srgb_to_lab`].
+fn lab_to_linear_srgb(lab: Vec3) -> Vec3 {
+    fn f_inv(t: f32) -> f32 {
+        const DELTA: f32 = 6.0 / 29.0;
+        if t > DELTA {
+            t * t * t
+        } else {
+            3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+        }
+    }
+
+    let fy = (lab.x + 16.0) / 116.0;
+    let fx = fy + lab.y / 500.0;
+    let fz = fy - lab.z / 200.0;
+
+    let xyz = Vec3::new(f_inv(fx), f_inv(fy), f_inv(fz)) * D65_WHITE;
+    xyz_to_linear_srgb(xyz)
+}
+
+fn hexcone(hue: f32, chroma: f32, m: f32) -> Vec3 {
+    let hue = hue.rem_euclid(360.0);
+    let h_prime = hue / 60.0;
+    let x = chroma * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+
+    let (r1, g1, b1) = if h_prime < 1.0 {
+        (chroma, x, 0.0)
+    } else if h_prime < 2.0 {
+        (x, chroma, 0.0)
+    } else if h_prime < 3.0 {
+        (0.0, chroma, x)
+    } else if h_prime < 4.0 {
+        (0.0, x, chroma)
+    } else if h_prime < 5.0 {
+        (x, 0.0, chroma)
+    } else {
+        (chroma, 0.0, x)
+    };
+
+    Vec3::new(r1 + m, g1 + m, b1 + m)
+}
+
+/// Returns `(hue_degrees, chroma, max, min)` for an RGB triple, with hue `0.0` when achromatic.
+fn hue_chroma(rgb: Vec3) -> (f32, f32, f32, f32) {
+    let max = rgb.max_element();
+    let min = rgb.min_element();
+    let chroma = max - min;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == rgb.x {
+        60.0 * (((rgb.y - rgb.z) / chroma).rem_euclid(6.0))
+    } else if max == rgb.y {
+        60.0 * ((rgb.z - rgb.x) / chroma + 2.0)
+    } else {
+        60.0 * ((rgb.x - rgb.y) / chroma + 4.0)
+    };
+
+    (hue.rem_euclid(360.0), chroma, max, min)
+}
+
+/// Hue-based color adjustments, mirroring palette's `Hue` trait.
+///
+/// Implementors with zero chroma/saturation are achromatic and have undefined hue, so
+/// [`shift_hue`][Hue::shift_hue] is a no-op on them.
+pub trait Hue {
+    /// Rotates the hue of `self` by `degrees`, wrapping modulo 360.
+    fn shift_hue(self, degrees: f32) -> Self;
+}
+
+/// Saturation adjustments, mirroring palette's `Saturate` trait.
+pub trait Saturate {
+    /// Increases saturation (or chroma) by `amount`, a factor typically in `[0..1]`.
+    fn saturate(self, amount: f32) -> Self;
+
+    /// Decreases saturation (or chroma) by `amount`, a factor typically in `[0..1]`.
+    fn desaturate(self, amount: f32) -> Self {
+        self.saturate(-amount)
+    }
+}
+
+/// Lightness adjustments, mirroring palette's `Shade` trait.
+pub trait Shade {
+    /// Increases lightness by `amount`, a factor typically in `[0..1]`.
+    fn lighten(self, amount: f32) -> Self;
+
+    /// Decreases lightness by `amount`, a factor typically in `[0..1]`.
+    fn darken(self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+}
+
+impl Hue for Hsl {
+    fn shift_hue(mut self, degrees: f32) -> Self {
+        if self.saturation != 0.0 {
+            self.hue = (self.hue + degrees).rem_euclid(360.0);
+        }
+        self
+    }
+}
+
+impl Saturate for Hsl {
+    fn saturate(mut self, amount: f32) -> Self {
+        self.saturation = (self.saturation + amount).clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Shade for Hsl {
+    fn lighten(mut self, amount: f32) -> Self {
+        self.lightness = (self.lightness + amount).clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Hue for Hsv {
+    fn shift_hue(mut self, degrees: f32) -> Self {
+        if self.saturation != 0.0 {
+            self.hue = (self.hue + degrees).rem_euclid(360.0);
+        }
+        self
+    }
+}
+
+impl Saturate for Hsv {
+    fn saturate(mut self, amount: f32) -> Self {
+        self.saturation = (self.saturation + amount).clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Shade for Hsv {
+    fn lighten(mut self, amount: f32) -> Self {
+        self.value = (self.value + amount).clamp(0.0, 1.0);
+        self
+    }
+}
+
+impl Hue for Lch {
+    fn shift_hue(mut self, degrees: f32) -> Self {
+        if self.chroma != 0.0 {
+            self.hue = (self.hue + degrees).rem_euclid(360.0);
+        }
+        self
+    }
+}
+
+impl Saturate for Lch {
+    fn saturate(mut self, amount: f32) -> Self {
+        self.chroma = (self.chroma + amount * 100.0).max(0.0);
+        self
+    }
+}
+
+impl Shade for Lch {
+    fn lighten(mut self, amount: f32) -> Self {
+        self.lightness = (self.lightness + amount * 100.0).clamp(0.0, 100.0);
+        self
+    }
+}
+
+impl Hue for Color<LinearSrgb, Display> {
+    fn shift_hue(self, degrees: f32) -> Self {
+        Hsl::from_linear_srgb(self).shift_hue(degrees).to_linear_srgb()
+    }
+}
+
+impl Saturate for Color<LinearSrgb, Display> {
+    fn saturate(self, amount: f32) -> Self {
+        Hsl::from_linear_srgb(self).saturate(amount).to_linear_srgb()
+    }
+}
+
+impl Shade for Color<LinearSrgb, Display> {
+    fn lighten(self, amount: f32) -> Self {
+        Hsl::from_linear_srgb(self).lighten(amount).to_linear_srgb()
+    }
+}
+
+impl Hue for ColorAlpha<LinearSrgb, Separate> {
+    /// Rotates this color's hue, leaving alpha untouched. A no-op on achromatic colors.
+    fn shift_hue(self, degrees: f32) -> Self {
+        with_color_channels(self, |c| c.shift_hue(degrees))
+    }
+}
+
+impl Saturate for ColorAlpha<LinearSrgb, Separate> {
+    /// Adjusts this color's saturation, leaving alpha untouched.
+    fn saturate(self, amount: f32) -> Self {
+        with_color_channels(self, |c| c.saturate(amount))
+    }
+}
+
+impl Shade for ColorAlpha<LinearSrgb, Separate> {
+    /// Adjusts this color's lightness, leaving alpha untouched.
+    fn lighten(self, amount: f32) -> Self {
+        with_color_channels(self, |c| c.lighten(amount))
+    }
+}
+
+/// Applies `f` to the color channels of `color` while passing its alpha through unchanged,
+/// mirroring how [`ColorAlpha::blend`][crate::ColorAlpha::blend] only touches color, not alpha.
+fn with_color_channels(
+    color: ColorAlpha<LinearSrgb, Separate>,
+    f: impl FnOnce(Color<LinearSrgb, Display>) -> Color<LinearSrgb, Display>,
+) -> ColorAlpha<LinearSrgb, Separate> {
+    let alpha = color.raw.w;
+    let adjusted = f(Color::from_raw(color.raw.xyz()));
+    ColorAlpha::from_raw(adjusted.raw.extend(alpha))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    fn assert_vec3_close(a: Vec3, b: Vec3) {
+        assert!((a - b).abs().max_element() < 1e-3, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn red_to_hsl() {
+        let hsl = Hsl::from_linear_srgb(Color::from_raw(Vec3::new(1.0, 0.0, 0.0)));
+        assert_close(hsl.hue, 0.0);
+        assert_close(hsl.saturation, 1.0);
+        assert_close(hsl.lightness, 0.5);
+    }
+
+    #[test]
+    fn white_to_hsl_is_achromatic() {
+        let hsl = Hsl::from_linear_srgb(Color::from_raw(Vec3::ONE));
+        assert_close(hsl.saturation, 0.0);
+        assert_close(hsl.lightness, 1.0);
+    }
+
+    #[test]
+    fn black_to_hsl_is_achromatic() {
+        let hsl = Hsl::from_linear_srgb(Color::from_raw(Vec3::ZERO));
+        assert_close(hsl.saturation, 0.0);
+        assert_close(hsl.lightness, 0.0);
+    }
+
+    #[test]
+    fn hsl_round_trips_through_linear_srgb() {
+        let original = Hsl {
+            hue: 120.0,
+            saturation: 0.5,
+            lightness: 0.5,
+        };
+        let round_tripped = Hsl::from_linear_srgb(original.to_linear_srgb());
+
+        assert_close(original.hue, round_tripped.hue);
+        assert_close(original.saturation, round_tripped.saturation);
+        assert_close(original.lightness, round_tripped.lightness);
+    }
+
+    #[test]
+    fn red_to_hsv() {
+        let hsv = Hsv::from_linear_srgb(Color::from_raw(Vec3::new(1.0, 0.0, 0.0)));
+        assert_close(hsv.hue, 0.0);
+        assert_close(hsv.saturation, 1.0);
+        assert_close(hsv.value, 1.0);
+    }
+
+    #[test]
+    fn white_to_hsv_is_achromatic() {
+        let hsv = Hsv::from_linear_srgb(Color::from_raw(Vec3::ONE));
+        assert_close(hsv.saturation, 0.0);
+        assert_close(hsv.value, 1.0);
+    }
+
+    #[test]
+    fn hsv_round_trips_through_linear_srgb() {
+        let original = Hsv {
+            hue: 200.0,
+            saturation: 0.75,
+            value: 0.4,
+        };
+        let round_tripped = Hsv::from_linear_srgb(original.to_linear_srgb());
+
+        assert_close(original.hue, round_tripped.hue);
+        assert_close(original.saturation, round_tripped.saturation);
+        assert_close(original.value, round_tripped.value);
+    }
+
+    #[test]
+    fn white_to_lch_is_achromatic_and_full_lightness() {
+        let lch = Lch::from_linear_srgb(Color::from_raw(Vec3::ONE));
+        assert_close(lch.chroma, 0.0);
+        assert_close(lch.lightness, 100.0);
+    }
+
+    #[test]
+    fn black_to_lch_is_zero_lightness() {
+        let lch = Lch::from_linear_srgb(Color::from_raw(Vec3::ZERO));
+        assert_close(lch.chroma, 0.0);
+        assert_close(lch.lightness, 0.0);
+    }
+
+    #[test]
+    fn lch_round_trips_through_linear_srgb() {
+        let original = Vec3::new(0.8, 0.2, 0.4);
+        let lch = Lch::from_linear_srgb(Color::from_raw(original));
+        let round_tripped = lch.to_linear_srgb().raw;
+
+        assert_vec3_close(original, round_tripped);
+    }
+
+    #[test]
+    fn lab_round_trips_through_lch() {
+        let lab = Vec3::new(50.0, 20.0, -30.0);
+        let round_tripped = Lch::from_lab(lab).to_lab();
+
+        assert_vec3_close(lab, round_tripped);
+    }
+
+    #[test]
+    fn shift_hue_is_noop_on_achromatic_hsl() {
+        let gray = Hsl {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.5,
+        };
+
+        assert_eq!(gray.shift_hue(123.0).hue, gray.hue);
+    }
+
+    #[test]
+    fn color_shift_hue_round_trips_via_hsl_sugar() {
+        let red = Color::<LinearSrgb, Display>::from_raw(Vec3::new(1.0, 0.0, 0.0));
+        let rotated = red.shift_hue(120.0);
+        let expected = Hsl::from_linear_srgb(red).shift_hue(120.0).to_linear_srgb();
+
+        assert_vec3_close(rotated.raw, expected.raw);
+    }
+}
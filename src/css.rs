@@ -0,0 +1,404 @@
+//! CSS Color Module parsing and serialization for [`DynamicColorAlpha`].
+//!
+//! Supports the subset of the [CSS Color Module Level 4](https://www.w3.org/TR/css-color-4/)
+//! syntax needed to round-trip colors through configuration files and user input: hex notation,
+//! `rgb()`/`hsl()` functions (both legacy comma and modern slash-alpha syntax), and the
+//! predefined-space `color()` function for `srgb`/`srgb-linear`.
+
+extern crate alloc;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use glam::Vec4;
+
+use crate::{
+    color_space::cylindrical::Hsl, ColorResult, DynamicAlphaState, DynamicColorAlpha,
+    DynamicColorSpace,
+};
+
+/// An error produced while parsing a CSS color string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CssParseError {
+    /// The string didn't match any supported CSS color syntax.
+    UnrecognizedSyntax,
+    /// A `color()` function named a predefined color space that isn't supported.
+    UnknownColorSpace(String),
+    /// A component (e.g. inside `rgb(...)`) couldn't be parsed as a number or percentage.
+    InvalidComponent(String),
+    /// A function call didn't have the expected number of components.
+    WrongArity { expected: usize, got: usize },
+}
+
+impl core::fmt::Display for CssParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CssParseError::UnrecognizedSyntax => write!(f, "unrecognized CSS color syntax"),
+            CssParseError::UnknownColorSpace(name) => {
+                write!(f, "unknown predefined color space `{name}`")
+            }
+            CssParseError::InvalidComponent(s) => write!(f, "invalid color component `{s}`"),
+            CssParseError::WrongArity { expected, got } => {
+                write!(f, "expected {expected} components, got {got}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CssParseError {}
+
+impl DynamicColorAlpha {
+    /// Parses a CSS Color Module color string into a [`DynamicColorAlpha`].
+    ///
+    /// Percentages (`50%`) map to `0.5`, `none` components parse to `0.0`, and out-of-range
+    /// values are preserved rather than clamped, matching the CSS spec's "missing/out of gamut
+    /// is not an error" philosophy.
+    pub fn parse_css(s: &str) -> ColorResult<Self> {
+        let s = s.trim();
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+
+        let (name, args) = split_function(s).ok_or(CssParseError::UnrecognizedSyntax)?;
+
+        match name.as_str() {
+            "rgb" | "rgba" => parse_rgb(&args),
+            "hsl" | "hsla" => parse_hsl(&args),
+            "color" => parse_color_fn(&args),
+            _ => Err(CssParseError::UnrecognizedSyntax.into()),
+        }
+    }
+
+    /// Serializes `self` using the modern `color()` function syntax, e.g.
+    /// `color(srgb 1 0.5 0 / 0.8)`.
+    pub fn to_css(self) -> String {
+        let (space_name, dst_space) = if self.space == DynamicColorSpace::LinearSrgb {
+            ("srgb-linear", DynamicColorSpace::LinearSrgb)
+        } else {
+            ("srgb", DynamicColorSpace::EncodedSrgb)
+        };
+
+        let raw = self
+            .convert(dst_space, DynamicAlphaState::Separate)
+            .raw;
+
+        format!(
+            "color({} {} {} {} / {})",
+            space_name, raw.x, raw.y, raw.z, raw.w
+        )
+    }
+}
+
+fn split_function(s: &str) -> Option<(String, Vec<String>)> {
+    let open = s.find('(')?;
+    if !s.ends_with(')') {
+        return None;
+    }
+    let name = s[..open].trim().to_ascii_lowercase();
+    let inner = &s[open + 1..s.len() - 1];
+
+    // Modern syntax separates components with spaces and an optional `/ alpha`; legacy syntax
+    // uses commas throughout. Normalize `/` to a separator token so both can share a tokenizer.
+    let inner = inner.replace(',', " ").replace('/', " ");
+    let args = inner.split_whitespace().map(str::to_string).collect();
+
+    Some((name, args))
+}
+
+fn parse_component(s: &str) -> Result<f32, CssParseError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+    if let Some(pct) = s.strip_suffix('%') {
+        return pct
+            .parse::<f32>()
+            .map(|p| p / 100.0)
+            .map_err(|_| CssParseError::InvalidComponent(s.to_string()));
+    }
+    s.parse::<f32>()
+        .map_err(|_| CssParseError::InvalidComponent(s.to_string()))
+}
+
+fn parse_hex(hex: &str) -> ColorResult<DynamicColorAlpha> {
+    // `bytes.len()` below counts bytes, and the arms index/slice by byte offset, so reject
+    // non-ASCII up front rather than risk slicing a multi-byte char in half.
+    if !hex.is_ascii() {
+        return Err(CssParseError::UnrecognizedSyntax.into());
+    }
+
+    fn hex_pair(s: &str) -> Option<u8> {
+        u8::from_str_radix(s, 16).ok()
+    }
+    fn hex_nibble(c: u8) -> Option<u8> {
+        (c as char).to_digit(16).map(|d| (d * 17) as u8)
+    }
+
+    let bytes = hex.as_bytes();
+    let [r, g, b, a] = match bytes.len() {
+        3 => [
+            hex_nibble(bytes[0]),
+            hex_nibble(bytes[1]),
+            hex_nibble(bytes[2]),
+            Some(255),
+        ],
+        4 => [
+            hex_nibble(bytes[0]),
+            hex_nibble(bytes[1]),
+            hex_nibble(bytes[2]),
+            hex_nibble(bytes[3]),
+        ],
+        6 => [
+            hex_pair(&hex[0..2]),
+            hex_pair(&hex[2..4]),
+            hex_pair(&hex[4..6]),
+            Some(255),
+        ],
+        8 => [
+            hex_pair(&hex[0..2]),
+            hex_pair(&hex[2..4]),
+            hex_pair(&hex[4..6]),
+            hex_pair(&hex[6..8]),
+        ],
+        _ => return Err(CssParseError::UnrecognizedSyntax.into()),
+    }
+    .map(|opt| opt.ok_or(CssParseError::UnrecognizedSyntax))
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()
+    .map(|v| [v[0], v[1], v[2], v[3]])?;
+
+    let raw = Vec4::new(
+        r as f32 / 255.0,
+        g as f32 / 255.0,
+        b as f32 / 255.0,
+        a as f32 / 255.0,
+    );
+
+    Ok(DynamicColorAlpha::new(
+        raw,
+        DynamicColorSpace::EncodedSrgb,
+        DynamicAlphaState::Separate,
+    ))
+}
+
+fn parse_rgb(args: &[String]) -> ColorResult<DynamicColorAlpha> {
+    if args.len() != 3 && args.len() != 4 {
+        return Err(CssParseError::WrongArity {
+            expected: 3,
+            got: args.len(),
+        }
+        .into());
+    }
+
+    let rgb_component = |s: &str| -> Result<f32, CssParseError> {
+        if let Some(pct) = s.strip_suffix('%') {
+            pct.parse::<f32>()
+                .map(|p| p / 100.0)
+                .map_err(|_| CssParseError::InvalidComponent(s.to_string()))
+        } else if s.eq_ignore_ascii_case("none") {
+            Ok(0.0)
+        } else {
+            s.parse::<f32>()
+                .map(|v| v / 255.0)
+                .map_err(|_| CssParseError::InvalidComponent(s.to_string()))
+        }
+    };
+
+    let r = rgb_component(&args[0])?;
+    let g = rgb_component(&args[1])?;
+    let b = rgb_component(&args[2])?;
+    let a = if args.len() == 4 {
+        parse_component(&args[3])?
+    } else {
+        1.0
+    };
+
+    Ok(DynamicColorAlpha::new(
+        Vec4::new(r, g, b, a),
+        DynamicColorSpace::EncodedSrgb,
+        DynamicAlphaState::Separate,
+    ))
+}
+
+/// Parses the `saturation`/`lightness` components of `hsl()`, which the CSS spec always scales
+/// as percentages (`50` and `50%` are both `0.5`), unlike `rgb()`'s alpha or `color()`'s
+/// components where a bare number is already a direct `[0..1]` value.
+fn parse_hsl_percent_component(s: &str) -> Result<f32, CssParseError> {
+    if s.eq_ignore_ascii_case("none") {
+        return Ok(0.0);
+    }
+    s.strip_suffix('%')
+        .unwrap_or(s)
+        .parse::<f32>()
+        .map(|p| p / 100.0)
+        .map_err(|_| CssParseError::InvalidComponent(s.to_string()))
+}
+
+fn parse_hsl(args: &[String]) -> ColorResult<DynamicColorAlpha> {
+    if args.len() != 3 && args.len() != 4 {
+        return Err(CssParseError::WrongArity {
+            expected: 3,
+            got: args.len(),
+        }
+        .into());
+    }
+
+    let hue = if args[0].eq_ignore_ascii_case("none") {
+        0.0
+    } else {
+        args[0]
+            .trim_end_matches("deg")
+            .parse::<f32>()
+            .map_err(|_| CssParseError::InvalidComponent(args[0].clone()))?
+    };
+    let saturation = parse_hsl_percent_component(&args[1])?;
+    let lightness = parse_hsl_percent_component(&args[2])?;
+    let alpha = if args.len() == 4 {
+        parse_component(&args[3])?
+    } else {
+        1.0
+    };
+
+    let srgb = Hsl {
+        hue,
+        saturation,
+        lightness,
+    }
+    .to_linear_srgb();
+
+    Ok(DynamicColorAlpha::new(
+        srgb.raw.extend(alpha),
+        DynamicColorSpace::LinearSrgb,
+        DynamicAlphaState::Separate,
+    ))
+}
+
+fn parse_color_fn(args: &[String]) -> ColorResult<DynamicColorAlpha> {
+    if args.is_empty() {
+        return Err(CssParseError::UnrecognizedSyntax.into());
+    }
+
+    let space = match args[0].as_str() {
+        "srgb" => DynamicColorSpace::EncodedSrgb,
+        "srgb-linear" => DynamicColorSpace::LinearSrgb,
+        other => return Err(CssParseError::UnknownColorSpace(other.to_string()).into()),
+    };
+
+    let components = &args[1..];
+    if components.len() != 3 && components.len() != 4 {
+        return Err(CssParseError::WrongArity {
+            expected: 3,
+            got: components.len(),
+        }
+        .into());
+    }
+
+    let r = parse_component(&components[0])?;
+    let g = parse_component(&components[1])?;
+    let b = parse_component(&components[2])?;
+    let a = if components.len() == 4 {
+        parse_component(&components[3])?
+    } else {
+        1.0
+    };
+
+    Ok(DynamicColorAlpha::new(
+        Vec4::new(r, g, b, a),
+        space,
+        DynamicAlphaState::Separate,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vec4_close(a: Vec4, b: Vec4) {
+        assert!((a - b).abs().max_element() < 1e-3, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn parses_short_hex() {
+        let c = DynamicColorAlpha::parse_css("#f00").unwrap();
+        assert_eq!(c.space, DynamicColorSpace::EncodedSrgb);
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn parses_short_hex_with_alpha() {
+        let c = DynamicColorAlpha::parse_css("#f008").unwrap();
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.0, 0.0, 136.0 / 255.0));
+    }
+
+    #[test]
+    fn parses_long_hex() {
+        let c = DynamicColorAlpha::parse_css("#ff0000").unwrap();
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn rejects_non_ascii_hex_instead_of_panicking() {
+        // Byte length 6 (so it reaches the `&hex[0..2]`-style slicing arm, not the `_` catch-all),
+        // with the multi-byte '€' straddling the `[2..4]` slice boundary: without the `is_ascii()`
+        // guard this would panic on a non-char-boundary slice rather than return `Err`.
+        assert!(DynamicColorAlpha::parse_css("#a€bc").is_err());
+    }
+
+    #[test]
+    fn parses_legacy_comma_rgb() {
+        let c = DynamicColorAlpha::parse_css("rgb(255, 0, 0)").unwrap();
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn parses_modern_slash_alpha_rgb() {
+        let c = DynamicColorAlpha::parse_css("rgb(255 0 0 / 50%)").unwrap();
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.0, 0.0, 0.5));
+    }
+
+    #[test]
+    fn parses_hsl_percentages() {
+        let c = DynamicColorAlpha::parse_css("hsl(120, 50%, 50%)").unwrap();
+        assert_eq!(c.space, DynamicColorSpace::LinearSrgb);
+        // hsl(120, 50%, 50%) is a mid green; saturation/lightness must be read as 0.5, not 50.0.
+        assert!(c.raw.x < c.raw.y && c.raw.z < c.raw.y);
+        assert!(c.raw.max_element() <= 1.0 && c.raw.min_element() >= 0.0);
+    }
+
+    #[test]
+    fn hsl_bare_numbers_are_percentages_like_the_percent_form() {
+        let with_percent = DynamicColorAlpha::parse_css("hsl(120, 50%, 50%)").unwrap();
+        let bare = DynamicColorAlpha::parse_css("hsl(120, 50, 50)").unwrap();
+        assert_vec4_close(with_percent.raw, bare.raw);
+    }
+
+    #[test]
+    fn parses_predefined_color_fn() {
+        let c = DynamicColorAlpha::parse_css("color(srgb 1 0.5 0 / 0.8)").unwrap();
+        assert_eq!(c.space, DynamicColorSpace::EncodedSrgb);
+        assert_vec4_close(c.raw, Vec4::new(1.0, 0.5, 0.0, 0.8));
+    }
+
+    #[test]
+    fn to_css_round_trips_through_parse_css() {
+        let original = DynamicColorAlpha::parse_css("color(srgb 1 0.5 0 / 0.8)").unwrap();
+        let css = original.to_css();
+        let reparsed = DynamicColorAlpha::parse_css(&css).unwrap();
+
+        assert_eq!(original.space, reparsed.space);
+        assert_vec4_close(original.raw, reparsed.raw);
+    }
+
+    #[test]
+    fn unknown_color_space_is_an_error() {
+        let err = DynamicColorAlpha::parse_css("color(display-p3 1 1 1)").unwrap_err();
+        assert!(matches!(
+            err,
+            crate::error::ColorError::Css(CssParseError::UnknownColorSpace(_))
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use core::fmt;
+
+use crate::{css::CssParseError, DynamicAlphaState, DynamicColorSpace};
+
+/// The result type used throughout this crate for fallible conversions.
+pub type ColorResult<T> = Result<T, ColorError>;
+
+/// The error type returned by this crate's fallible operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorError {
+    /// Attempted to downcast a type-erased color to a mismatched typed [`ColorSpace`][crate::ColorSpace]
+    /// or [`AlphaState`][crate::AlphaState].
+    Downcast(DowncastError),
+    /// Failed to parse a CSS color string.
+    Css(CssParseError),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::Downcast(e) => write!(f, "{e}"),
+            ColorError::Css(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ColorError {}
+
+/// An error produced when downcasting a type-erased color (e.g. [`DynamicColorAlpha`][crate::DynamicColorAlpha])
+/// to a statically typed [`ColorAlpha`][crate::ColorAlpha] or [`Color`][crate::Color].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DowncastError {
+    /// The type-erased color's space didn't match the requested static [`ColorSpace`][crate::ColorSpace].
+    MismatchedSpace(DynamicColorSpace, DynamicColorSpace),
+    /// The type-erased color's alpha state didn't match the requested static [`AlphaState`][crate::AlphaState].
+    MismatchedAlphaState(DynamicAlphaState, DynamicAlphaState),
+}
+
+impl fmt::Display for DowncastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DowncastError::MismatchedSpace(got, expected) => {
+                write!(f, "mismatched color space: expected {expected}, got {got}")
+            }
+            DowncastError::MismatchedAlphaState(got, expected) => {
+                write!(
+                    f,
+                    "mismatched alpha state: expected {expected}, got {got}"
+                )
+            }
+        }
+    }
+}
+
+impl From<DowncastError> for ColorError {
+    fn from(e: DowncastError) -> Self {
+        ColorError::Downcast(e)
+    }
+}
+
+impl From<CssParseError> for ColorError {
+    fn from(e: CssParseError) -> Self {
+        ColorError::Css(e)
+    }
+}